@@ -0,0 +1,51 @@
+import os
+import signal
+import subprocess as sp
+import sys
+import tempfile
+import time
+import unittest
+
+RUNC = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "runc")
+
+
+def alive(pid: int) -> bool:
+    try:
+        with open(f"/proc/{pid}/stat") as f:
+            # Zombies are dead, they are only waiting for init to reap them
+            return f.read().rsplit(")", 1)[1].split()[0] != "Z"
+    except FileNotFoundError:
+        return False
+
+
+@unittest.skipUnless(sys.platform == "linux", "reads /proc")
+class Lifetime(unittest.TestCase):
+    def testNothingOutlivesKilledRunc(self) -> None:
+        with tempfile.TemporaryDirectory() as dir:
+            pids = os.path.join(dir, "pids")
+            snippet = os.path.join(dir, "snippet.sh")
+            with open(snippet, "w") as f:
+                f.write(f"sleep 371 &\necho $! >> {pids}\nsh -c 'echo $$ >> {pids}; exec sleep 372' &\n"
+                        f"echo $$ >> {pids}\nsleep 373\n")
+            runc = sp.Popen([sys.executable, RUNC, "sh", "--file", snippet],
+                            stdout=sp.DEVNULL, stderr=sp.DEVNULL, env=os.environ | {"XDG_CACHE_HOME": dir})
+            try:
+                deadline = time.monotonic() + 10
+                while not (os.path.exists(pids) and len(open(pids).read().split()) == 3):
+                    self.assertLess(time.monotonic(), deadline, "the snippet did not start")
+                    time.sleep(0.05)
+            finally:
+                runc.send_signal(signal.SIGKILL)
+                runc.wait()
+            with open(pids) as f:
+                descendants = [int(pid) for pid in f.read().split()]
+            deadline = time.monotonic() + 5
+            while (survivors := [pid for pid in descendants if alive(pid)]) and time.monotonic() < deadline:
+                time.sleep(0.05)
+            for pid in survivors:
+                os.kill(pid, signal.SIGKILL)
+            self.assertEqual(survivors, [])
+
+
+if __name__ == "__main__":
+    unittest.main()